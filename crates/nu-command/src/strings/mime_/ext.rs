@@ -0,0 +1,133 @@
+use nu_engine::command_prelude::*;
+
+const NO_SPAN: Span = Span::unknown();
+
+fn extensions_for(mime_type: &str, span: Span) -> Value {
+    let extensions = mime_guess::get_mime_extensions_str(mime_type)
+        .map(|extensions| {
+            extensions
+                .iter()
+                .map(|ext| Value::string(*ext, span))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Value::list(extensions, span)
+}
+
+#[derive(Clone)]
+pub struct MimeExt;
+
+impl Command for MimeExt {
+    fn name(&self) -> &str {
+        "mime ext"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::List(Box::new(Type::String))),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::Table(Box::new([
+                        ("type".to_string(), Type::String),
+                        (
+                            "extensions".to_string(),
+                            Type::List(Box::new(Type::String)),
+                        ),
+                    ])),
+                ),
+            ])
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Look up the known file extensions for a MIME/Media Type."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The inverse of `mime guess`: given a MIME type, return the extensions commonly used for it. Unrecognized types return an empty list."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: r#""image/webp" | mime ext"#,
+                description: "Look up the extensions used for a MIME type.",
+                result: Some(Value::list(
+                    vec![Value::string("webp", NO_SPAN)],
+                    NO_SPAN,
+                )),
+            },
+            Example {
+                example: r#"["video/x-matroska" "audio/mpeg"] | mime ext"#,
+                description: "Look up the extensions for several MIME types and return a table.",
+                result: Some(Value::list(
+                    vec![
+                        Value::record(
+                            record!(
+                                "type" => Value::string("video/x-matroska", NO_SPAN),
+                                "extensions" => Value::list(vec![Value::string("mkv", NO_SPAN)], NO_SPAN),
+                            ),
+                            NO_SPAN,
+                        ),
+                        Value::record(
+                            record!(
+                                "type" => Value::string("audio/mpeg", NO_SPAN),
+                                "extensions" => Value::list(vec![Value::string("mp3", NO_SPAN)], NO_SPAN),
+                            ),
+                            NO_SPAN,
+                        ),
+                    ],
+                    NO_SPAN,
+                )),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        match input {
+            PipelineData::Value(Value::String { val, internal_span }, ..) => {
+                Ok(extensions_for(&val, internal_span).into_pipeline_data())
+            }
+            PipelineData::Value(Value::List { .. }, ..) | PipelineData::ListStream(..) => {
+                let records_iter = input.into_iter().map(move |value| {
+                    let span = value.span();
+
+                    match value.as_str() {
+                        Ok(s) => {
+                            let mime_type = Value::string(s, span);
+                            let extensions = extensions_for(s, span);
+
+                            Value::record(
+                                record!("type" => mime_type, "extensions" => extensions),
+                                span,
+                            )
+                        }
+                        Err(err) => Value::error(
+                            ShellError::TypeMismatch {
+                                err_message: err.to_string(),
+                                span,
+                            },
+                            span,
+                        ),
+                    }
+                });
+
+                let ctrlc = engine_state.ctrlc.clone();
+
+                Ok(records_iter.into_pipeline_data(ctrlc))
+            }
+            _ => Err(ShellError::TypeMismatch {
+                err_message: "Only string input is supported".to_string(),
+                span: input.span().unwrap_or(NO_SPAN),
+            }),
+        }
+    }
+}
@@ -14,6 +14,7 @@ impl Command for MimeGuess {
         Signature::build(self.name())
             .input_output_types(vec![
                 (Type::String, Type::String),
+                (Type::String, Type::List(Box::new(Type::String))),
                 (
                     Type::List(Box::new(Type::String)),
                     Type::Table(Box::new([
@@ -21,12 +22,24 @@ impl Command for MimeGuess {
                         ("type".to_string(), Type::String),
                     ])),
                 ),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::Table(Box::new([
+                        ("name".to_string(), Type::String),
+                        ("type".to_string(), Type::List(Box::new(Type::String))),
+                    ])),
+                ),
             ])
             .switch(
                 "extension",
                 "Accept extensions as input rather than file paths",
                 Some('e'),
             )
+            .switch(
+                "all",
+                "Return every candidate MIME type instead of just the first",
+                Some('a'),
+            )
             .category(Category::Strings)
     }
 
@@ -90,6 +103,17 @@ impl Command for MimeGuess {
                     NO_SPAN,
                 )),
             },
+            Example {
+                example: r#""video.mp4" | mime guess --all"#,
+                description: "Return every candidate MIME type for an ambiguous extension.",
+                result: Some(Value::list(
+                    vec![
+                        Value::string("video/mp4", NO_SPAN),
+                        Value::string("audio/mp4", NO_SPAN),
+                    ],
+                    NO_SPAN,
+                )),
+            },
         ]
     }
 
@@ -101,6 +125,7 @@ impl Command for MimeGuess {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let use_extension: bool = call.has_flag(engine_state, stack, "extension")?;
+        let return_all: bool = call.has_flag(engine_state, stack, "all")?;
 
         let guess_function: fn(&str) -> mime_guess::MimeGuess = if use_extension {
             mime_guess::from_ext
@@ -110,14 +135,34 @@ impl Command for MimeGuess {
             |input| mime_guess::from_path(input)
         };
 
+        let mime_types = move |s: &str, span: Span| -> Value {
+            let guess = guess_function(s);
+
+            if return_all {
+                let types: Vec<Value> = guess
+                    .iter()
+                    .map(|mime| Value::string(mime.to_string(), span))
+                    .collect();
+
+                if types.is_empty() {
+                    Value::list(vec![Value::string("unknown", span)], span)
+                } else {
+                    Value::list(types, span)
+                }
+            } else {
+                Value::string(
+                    guess
+                        .first()
+                        .map(|mime| mime.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    span,
+                )
+            }
+        };
+
         match input {
             PipelineData::Value(Value::String { val, internal_span }, ..) => {
-                let mime_type = guess_function(&val)
-                    .first()
-                    .map(|mime| mime.to_string())
-                    .unwrap_or_else(|| "unknown".to_string());
-
-                Ok(Value::string(mime_type, internal_span).into_pipeline_data())
+                Ok(mime_types(&val, internal_span).into_pipeline_data())
             }
             PipelineData::Value(Value::List { .. }, ..) | PipelineData::ListStream(..) => {
                 let mime_records_iter = input.into_iter().map(move |value| {
@@ -126,13 +171,7 @@ impl Command for MimeGuess {
                     match value.as_str() {
                         Ok(s) => {
                             let name = Value::string(s, span);
-                            let mime_type = Value::string(
-                                guess_function(s)
-                                    .first()
-                                    .map(|mime| mime.to_string())
-                                    .unwrap_or_else(|| "unknown".to_string()),
-                                span,
-                            );
+                            let mime_type = mime_types(s, span);
 
                             Value::record(record!("name" => name, "type" => mime_type), span)
                         }
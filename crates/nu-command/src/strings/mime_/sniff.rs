@@ -0,0 +1,152 @@
+use std::fs::File;
+use std::io::Read;
+
+use nu_engine::command_prelude::*;
+
+const NO_SPAN: Span = Span::unknown();
+
+/// Number of leading bytes read from each file before matching signatures.
+const SNIFF_LEN: usize = 8 * 1024;
+
+/// `(offset, pattern, mime type)`. The first entry whose pattern matches the bytes at `offset`
+/// wins, so more specific signatures should come before more general ones.
+const SIGNATURES: &[(usize, &[u8], &str)] = &[
+    (0, &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A], "image/png"),
+    (0, &[0xFF, 0xD8, 0xFF], "image/jpeg"),
+    (0, b"GIF87a", "image/gif"),
+    (0, b"GIF89a", "image/gif"),
+    (0, b"%PDF", "application/pdf"),
+    (0, &[0x50, 0x4B, 0x03, 0x04], "application/zip"),
+    (0, &[0x1F, 0x8B], "application/gzip"),
+    // WebM is a profile of Matroska and shares its EBML header, so this cannot be
+    // disambiguated from bytes alone. Map it the same way `mime guess` maps `.mkv`.
+    (0, &[0x1A, 0x45, 0xDF, 0xA3], "video/x-matroska"),
+    (0, &[0x49, 0x44, 0x33], "audio/mpeg"),
+    (0, &[0xFF, 0xFB], "audio/mpeg"),
+    (0, &[0x7F, 0x45, 0x4C, 0x46], "application/x-executable"),
+];
+
+/// Detect the MIME type of `buf` (the first [`SNIFF_LEN`] bytes of a file) from its magic
+/// signature, falling back to `"unknown"` when nothing matches.
+fn sniff_bytes(buf: &[u8]) -> &'static str {
+    if buf.len() >= 12 && &buf[0..4] == b"RIFF" && &buf[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+
+    for (offset, pattern, mime) in SIGNATURES {
+        if buf.len() >= offset + pattern.len() && &buf[*offset..*offset + pattern.len()] == *pattern
+        {
+            return mime;
+        }
+    }
+
+    "unknown"
+}
+
+/// Read up to [`SNIFF_LEN`] bytes from `path` and guess its MIME type from their content.
+pub(crate) fn sniff_path(path: &str) -> String {
+    let Ok(mut file) = File::open(path) else {
+        return "unknown".to_string();
+    };
+
+    let mut buf = vec![0u8; SNIFF_LEN];
+    let read = match file.read(&mut buf) {
+        Ok(read) => read,
+        Err(_) => return "unknown".to_string(),
+    };
+    buf.truncate(read);
+
+    sniff_bytes(&buf).to_string()
+}
+
+#[derive(Clone)]
+pub struct MimeSniff;
+
+impl Command for MimeSniff {
+    fn name(&self) -> &str {
+        "mime sniff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::Table(Box::new([
+                        ("name".to_string(), Type::String),
+                        ("type".to_string(), Type::String),
+                    ])),
+                ),
+            ])
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Guess the MIME/Media Type of a file from its content rather than its name."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Reads the first 8 KiB of each path and matches it against known magic-byte signatures, so the result reflects what a file actually is even if it has been renamed or has no extension. Paths that cannot be opened, or whose content matches no known signature, return "unknown"."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: r#""photo.txt" | mime sniff"#,
+                description: "Detect the real MIME type of a mislabeled file.",
+                // Touches the filesystem, so the result can't be asserted here.
+                result: None,
+            },
+            Example {
+                example: r#"["a.bin" "b.bin"] | mime sniff"#,
+                description: "Sniff the MIME types of several paths and return a table.",
+                // Touches the filesystem, so the result can't be asserted here.
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        match input {
+            PipelineData::Value(Value::String { val, internal_span }, ..) => {
+                Ok(Value::string(sniff_path(&val), internal_span).into_pipeline_data())
+            }
+            PipelineData::Value(Value::List { .. }, ..) | PipelineData::ListStream(..) => {
+                let mime_records_iter = input.into_iter().map(move |value| {
+                    let span = value.span();
+
+                    match value.as_str() {
+                        Ok(s) => {
+                            let name = Value::string(s, span);
+                            let mime_type = Value::string(sniff_path(s), span);
+
+                            Value::record(record!("name" => name, "type" => mime_type), span)
+                        }
+                        Err(err) => Value::error(
+                            ShellError::TypeMismatch {
+                                err_message: err.to_string(),
+                                span,
+                            },
+                            span,
+                        ),
+                    }
+                });
+
+                let ctrlc = engine_state.ctrlc.clone();
+
+                Ok(mime_records_iter.into_pipeline_data(ctrlc))
+            }
+            _ => Err(ShellError::TypeMismatch {
+                err_message: "Only string input is supported".to_string(),
+                span: input.span().unwrap_or(NO_SPAN),
+            }),
+        }
+    }
+}